@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::config::{Client, Response};
+use crate::params::List;
+
+/// A billing meter, used to aggregate customer usage for metered prices.
+///
+/// For more details see [https://stripe.com/docs/api/billing/meter](https://stripe.com/docs/api/billing/meter).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingMeter {
+    /// Unique identifier for the object.
+    pub id: String,
+
+    /// The meter's name.
+    pub display_name: String,
+
+    /// The name of the meter event to record usage for.
+    pub event_name: String,
+
+    /// The meter's status, either `active` or `inactive`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// The default settings to aggregate a meter's events with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_aggregation: Option<BillingMeterDefaultAggregation>,
+
+    /// The settings that describe where to find the value that a meter event uses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_settings: Option<BillingMeterValueSettings>,
+
+    /// The settings that describe how to map a meter event to a customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_mapping: Option<BillingMeterCustomerMapping>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BillingMeterDefaultAggregation {
+    /// Specifies how events are aggregated.
+    pub formula: BillingMeterAggregationFormula,
+}
+
+/// Specifies how meter events are aggregated.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingMeterAggregationFormula {
+    Sum,
+    Count,
+}
+
+impl Default for BillingMeterAggregationFormula {
+    fn default() -> Self {
+        BillingMeterAggregationFormula::Sum
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BillingMeterValueSettings {
+    /// The key in the meter event payload to use as the value for this meter.
+    pub event_payload_key: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BillingMeterCustomerMapping {
+    /// The method for mapping a meter event to a customer.
+    ///
+    /// Must be `by_id`.
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// The key in the meter event payload to use for mapping the event to a customer.
+    pub event_payload_key: String,
+}
+
+/// The parameters for `BillingMeter::create`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingMeter<'a> {
+    /// The meter's name.
+    pub display_name: &'a str,
+
+    /// The name of the meter event to record usage for.
+    pub event_name: &'a str,
+
+    /// The default settings to aggregate a meter's events with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_aggregation: Option<BillingMeterDefaultAggregation>,
+
+    /// The settings that describe where to find the value that a meter event uses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_settings: Option<BillingMeterValueSettings>,
+
+    /// The settings that describe how to map a meter event to a customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_mapping: Option<BillingMeterCustomerMapping>,
+}
+
+/// The parameters for `BillingMeter::update`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateBillingMeter<'a> {
+    /// The meter's name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<&'a str>,
+}
+
+/// The parameters for `BillingMeter::list`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ListBillingMeters<'a> {
+    /// Filter results to only include meters with the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<&'a str>,
+
+    /// A limit on the number of objects to be returned, between 1 and 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+}
+
+impl BillingMeter {
+    /// Creates a billing meter.
+    pub fn create(client: &Client, params: CreateBillingMeter) -> Response<BillingMeter> {
+        client.post_form("/billing/meters", params)
+    }
+
+    /// Returns a list of billing meters.
+    pub fn list(client: &Client, params: ListBillingMeters) -> Response<List<BillingMeter>> {
+        client.get_query("/billing/meters", params)
+    }
+
+    /// Retrieves a billing meter.
+    pub fn retrieve(client: &Client, id: &str) -> Response<BillingMeter> {
+        client.get(&format!("/billing/meters/{}", id))
+    }
+
+    /// Updates a billing meter.
+    pub fn update(client: &Client, id: &str, params: UpdateBillingMeter) -> Response<BillingMeter> {
+        client.post_form(&format!("/billing/meters/{}", id), params)
+    }
+
+    /// Deactivates a billing meter.
+    pub fn deactivate(client: &Client, id: &str) -> Response<BillingMeter> {
+        client.post(&format!("/billing/meters/{}/deactivate", id))
+    }
+}
+
+/// A billing meter event, reporting usage for a metered price.
+///
+/// For more details see [https://stripe.com/docs/api/billing/meter-event](https://stripe.com/docs/api/billing/meter-event).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingMeterEvent {
+    /// The name of the meter event.
+    pub event_name: String,
+
+    /// The payload of the event.
+    pub payload: HashMap<String, String>,
+
+    /// A unique identifier for the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+
+    /// The time of the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<crate::Timestamp>,
+}
+
+/// The parameters for `BillingMeterEvent::create`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingMeterEvent<'a> {
+    /// The name of the meter event.
+    ///
+    /// Corresponds with the `event_name` field on a meter.
+    pub event_name: &'a str,
+
+    /// The payload of the event.
+    ///
+    /// This must contain the value to record and, when the meter uses the
+    /// default customer mapping, the `stripe_customer_id`.
+    pub payload: HashMap<String, String>,
+
+    /// A unique identifier for the event.
+    ///
+    /// If not provided, one is generated. Events are deduplicated on this value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<&'a str>,
+
+    /// The time of the event.
+    ///
+    /// Defaults to the current timestamp if not specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<crate::Timestamp>,
+}
+
+impl BillingMeterEvent {
+    /// Records a billing meter event.
+    pub fn create(client: &Client, params: CreateBillingMeterEvent) -> Response<BillingMeterEvent> {
+        client.post_form("/billing/meter_events", params)
+    }
+}
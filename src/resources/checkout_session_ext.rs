@@ -1,8 +1,8 @@
 use crate::config::{Client, Response};
 use crate::ids::CustomerId;
+use crate::params::Metadata;
 use crate::resources::{
-    CheckoutSession, CheckoutSessionLocale, CheckoutSessionMode, CheckoutSessionSubmitType,
-    Currency,
+    CheckoutSessionLocale, CheckoutSessionMode, CheckoutSessionSubmitType, Currency,
 };
 use serde_derive::{Deserialize, Serialize};
 
@@ -12,13 +12,37 @@ use serde_derive::{Deserialize, Serialize};
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateCheckoutSession<'a> {
     /// The URL the customer will be directed to if they decide to cancel payment and return to your website.
-    pub cancel_url: &'a str,
+    ///
+    /// Required when `ui_mode` is `hosted`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_url: Option<&'a str>,
 
     /// A list of the types of payment methods (e.g. card) this Checkout Session is allowed to accept. The only supported values today are `card` and `ideal`.
     pub payment_method_types: Vec<&'a str>,
 
     /// The URL the customer will be directed to after the payment or subscription creation is successful.
-    pub success_url: &'a str,
+    ///
+    /// Required when `ui_mode` is `hosted`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success_url: Option<&'a str>,
+
+    /// The UI mode of the Session.
+    ///
+    /// Defaults to `hosted` when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ui_mode: Option<CheckoutSessionUiMode>,
+
+    /// Configure whether a Checkout Session should be redirected to `return_url` after payment succeeds.
+    ///
+    /// Can only be used with `ui_mode` set to `embedded`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_on_completion: Option<&'a str>,
+
+    /// The URL to redirect your customer back to after they authenticate or cancel their payment on the payment method's app or site.
+    ///
+    /// Required when `ui_mode` is `embedded`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
 
     /// A unique string to reference the Checkout Session.
     ///
@@ -61,11 +85,14 @@ pub struct CreateCheckoutSession<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub discounts: Option<Vec<CheckoutDiscount>>,
 
-    // A subset of parameters to be passed to PaymentIntent creation for Checkout Sessions in payment mode
-    // TODO: payment_intent_data
+    /// A subset of parameters to be passed to PaymentIntent creation for Checkout Sessions in `payment` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_intent_data: Option<CreateCheckoutSessionPaymentIntentData>,
+
+    /// A subset of parameters to be passed to SetupIntent creation for Checkout Sessions in `setup` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_intent_data: Option<CreateCheckoutSessionSetupIntentData>,
 
-    // A subset of parameters to be passed to SetupIntent creation for Checkout Sessions in setup mode.
-    // TODO: setup_intent_data
     /// Describes the type of transaction being performed by Checkout in order
     /// to customize relevant text on the page, such as the submit button.
     /// `submit_type` can only be specified on Checkout Sessions using line
@@ -74,12 +101,64 @@ pub struct CreateCheckoutSession<'a> {
     /// Supported values are `auto`, `book`, `donate`, or `pay`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub submit_type: Option<CheckoutSessionSubmitType>,
-    // A subset of parameters to be passed to subscription creation for Checkout Sessions in subscription mode.
-    // TODO: subscription_data
+
+    /// A subset of parameters to be passed to subscription creation for Checkout Sessions in `subscription` mode.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription_data: Option<SubscriptionData>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub automatic_tax: Option<CheckoutAutomaticTax>,
+
+    /// When set, provides configuration for Checkout to collect a shipping address from a customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address_collection: Option<CheckoutShippingAddressCollection>,
+
+    /// The shipping rate options to apply to this Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_options: Option<Vec<CheckoutShippingOption>>,
+
+    /// Controls phone number collection settings for the session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number_collection: Option<CheckoutPhoneNumberCollection>,
+
+    /// Configure fields for the Checkout Session to gather active consent from customers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consent_collection: Option<CheckoutConsentCollection>,
+
+    /// The Epoch time in seconds at which the Checkout Session will expire.
+    ///
+    /// It can be anywhere from 30 minutes to 24 hours after Checkout Session creation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<crate::Timestamp>,
+
+    /// Configure actions after a Checkout Session has expired.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_expiration: Option<CreateCheckoutSessionAfterExpiration>,
+
+    /// Enables user redeemable promotion codes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_promotion_codes: Option<bool>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Collect additional information from your customer using custom fields.
+    ///
+    /// Up to 3 fields are supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CheckoutSessionCustomField>>,
+
+    /// Display additional text for your customers using custom text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_text: Option<CheckoutSessionCustomText>,
+}
+
+/// The UI mode of a Checkout Session.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionUiMode {
+    Hosted,
+    Embedded,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -92,24 +171,353 @@ pub struct CheckoutDiscount {
     pub coupon: Option<crate::CouponId>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutShippingAddressCollection {
+    /// An array of two-letter ISO country codes representing which countries Checkout should provide as options for shipping locations.
+    pub allowed_countries: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutShippingOption {
+    /// The ID of the Shipping Rate to use for this shipping option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_rate: Option<String>,
+
+    /// Parameters to be passed to Shipping Rate creation for this shipping option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_rate_data: Option<CheckoutShippingRateData>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutShippingRateData {
+    /// The name of the shipping rate, meant to be displayable to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// Describes a fixed amount to charge for shipping.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_amount: Option<CheckoutShippingRateFixedAmount>,
+
+    /// The estimated range for how long shipping will take, meant to be displayable to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_estimate: Option<CheckoutShippingRateDeliveryEstimate>,
+
+    /// Specifies whether the rate is considered inclusive of taxes or exclusive of taxes.
+    ///
+    /// One of `inclusive`, `exclusive`, or `unspecified`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutShippingRateFixedAmount {
+    /// A non-negative integer in cents representing how much to charge.
+    pub amount: i64,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    pub currency: Currency,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutShippingRateDeliveryEstimate {
+    /// The lower bound of the estimated range.
+    ///
+    /// If empty, represents no lower bound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<CheckoutDeliveryEstimateBound>,
+
+    /// The upper bound of the estimated range.
+    ///
+    /// If empty, represents no upper bound i.e., infinite.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<CheckoutDeliveryEstimateBound>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutDeliveryEstimateBound {
+    /// A unit of time.
+    ///
+    /// One of `hour`, `day`, `business_day`, `week`, or `month`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+
+    /// Must be greater than 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<i64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutPhoneNumberCollection {
+    /// Set to `true` to enable phone number collection.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomField {
+    /// Unique string of your choice that will identify this field in the resulting payload when retrieving the session.
+    pub key: String,
+
+    /// The type of the field.
+    #[serde(rename = "type")]
+    pub type_: CheckoutSessionCustomFieldType,
+
+    /// The label for the field, displayed to the customer.
+    pub label: CheckoutSessionCustomFieldLabel,
+
+    /// Configuration for `type=text` fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<CheckoutSessionCustomFieldText>,
+
+    /// Configuration for `type=numeric` fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numeric: Option<CheckoutSessionCustomFieldNumeric>,
+
+    /// Configuration for `type=dropdown` fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dropdown: Option<CheckoutSessionCustomFieldDropdown>,
+
+    /// Whether the customer may leave the field blank when completing the Checkout Session.
+    ///
+    /// Defaults to `false`, which makes the field required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionCustomFieldType {
+    Text,
+    Numeric,
+    Dropdown,
+}
+
+impl Default for CheckoutSessionCustomFieldType {
+    fn default() -> Self {
+        CheckoutSessionCustomFieldType::Text
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomFieldLabel {
+    /// Custom text for the label, displayed to the customer.
+    ///
+    /// Up to 50 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom: Option<String>,
+
+    /// The type of the label.
+    ///
+    /// Currently only `custom` is supported.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomFieldText {
+    /// The maximum character length constraint for the customer's input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_length: Option<i64>,
+
+    /// The minimum character length requirement for the customer's input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_length: Option<i64>,
+
+    /// The value entered by the customer, present on a retrieved session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomFieldNumeric {
+    /// The maximum character length constraint for the customer's input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_length: Option<i64>,
+
+    /// The minimum character length requirement for the customer's input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_length: Option<i64>,
+
+    /// The value entered by the customer, present on a retrieved session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomFieldDropdown {
+    /// The options available for the customer to select.
+    ///
+    /// Up to 200 options allowed.
+    pub options: Vec<CheckoutSessionCustomFieldDropdownOption>,
+
+    /// The option selected by the customer, present on a retrieved session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomFieldDropdownOption {
+    /// The label for the option, displayed to the customer.
+    ///
+    /// Up to 100 characters.
+    pub label: String,
+
+    /// The value for this option, not displayed to the customer, used by your integration to reconcile the option selected by the customer.
+    ///
+    /// Up to 100 characters.
+    pub value: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomText {
+    /// Custom text that should be displayed alongside shipping address collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<CheckoutSessionCustomTextPosition>,
+
+    /// Custom text that should be displayed alongside the payment confirmation button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<CheckoutSessionCustomTextPosition>,
+
+    /// Custom text that should be displayed in place of the default terms of service agreement text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms_of_service_acceptance: Option<CheckoutSessionCustomTextPosition>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomTextPosition {
+    /// Text may be up to 1200 characters in length.
+    pub message: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateCheckoutSessionAfterExpiration {
+    /// Configure a Checkout Session that can be used to recover an expired session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovery: Option<CreateCheckoutSessionAfterExpirationRecovery>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateCheckoutSessionAfterExpirationRecovery {
+    /// If `true`, a recovery URL will be generated to recover this Checkout Session if it expires before a successful transaction is completed.
+    pub enabled: bool,
+
+    /// Enables user redeemable promotion codes on the recovered Checkout Sessions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_promotion_codes: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutConsentCollection {
+    /// If set to `auto`, enables the collection of customer consent for promotional communications.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotions: Option<String>,
+
+    /// If set to `required`, it requires customers to check a terms of service checkbox before being able to pay.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms_of_service: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct SubscriptionData {
-    // A subset of parameters to be passed to subscription creation for Checkout Sessions in subscription mode.
-    // Hide child parameters
-    // application_fee_percent optional
-    // A non-negative decimal between 0 and 100, with at most two decimal places. This represents the percentage of the subscription invoice subtotal that will be transferred to the application owner’s Stripe account. To use an application fee percent, the request must be made on behalf of another account, using the Stripe-Account header or an OAuth key. For more information, see the application fees documentation.
-    // default_tax_rates optional
-    // A list of items, each with an attached plan, that the customer is subscribing to. Prefer using line_items.
-    // Show child parameters
-    // metadata optional dictionary
-    // Set of key-value pairs that you can attach to an object. This can be useful for storing additional information about the object in a structured format. Individual keys can be unset by posting an empty value to them. All keys can be unset by posting an empty value to metadata.
-    // transfer_data optional dictionary
-    // If specified, the funds from the subscription’s invoices will be transferred to the destination and the ID of the resulting transfers will be found on the resulting charges.
-    // trial_end optional
-    // Unix timestamp representing the end of the trial period the customer will get before being charged for the first time. Has to be at least 48 hours in the future.
+    /// A non-negative decimal between 0 and 100, with at most two decimal places.
+    ///
+    /// This represents the percentage of the subscription invoice subtotal that will be transferred to the application owner's Stripe account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_percent: Option<f64>,
+
+    /// The tax rates that will apply to any subscription item that does not have `tax_rates` set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<String>>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// If specified, the funds from the subscription's invoices will be transferred to the destination and the ID of the resulting transfers will be found on the resulting charges.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<SubscriptionDataTransferData>,
+
+    /// Unix timestamp representing the end of the trial period the customer will get before being charged for the first time.
+    ///
+    /// Has to be at least 48 hours in the future.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_end: Option<crate::Timestamp>,
+
+    /// Integer representing the number of trial period days before the customer is charged for the first time.
+    ///
+    /// Has to be at least 1.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_period_days: Option<i32>,
-    // Integer representing the number of trial period days before the customer is charged for the first time. Has to be at least 1.
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionDataTransferData {
+    /// ID of an existing, connected Stripe account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+
+    /// A non-negative decimal between 0 and 100, with at most two decimal places.
+    ///
+    /// This represents the percentage of the subscription invoice subtotal that will be transferred to the destination account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_percent: Option<f64>,
+}
+
+/// A subset of parameters to be passed to PaymentIntent creation for Checkout Sessions in `payment` mode.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateCheckoutSessionPaymentIntentData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_method: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_email: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<CreateCheckoutSessionPaymentIntentDataTransferData>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateCheckoutSessionPaymentIntentDataTransferData {
+    /// The amount that will be transferred automatically when a charge succeeds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// If specified, successful charges will be attributed to the destination account for tax reporting, and the funds from charges will be transferred to the destination account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+}
+
+/// A subset of parameters to be passed to SetupIntent creation for Checkout Sessions in `setup` mode.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateCheckoutSessionSetupIntentData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -139,11 +547,180 @@ pub struct CheckoutSessionLineItem<'a> {
     pub price: Option<crate::PriceId>,
 }
 
+impl<'a> CreateCheckoutSession<'a> {
+    /// Checks that the url parameters are consistent with the requested `ui_mode`.
+    ///
+    /// Hosted sessions require a `success_url`, embedded sessions require a `return_url`.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self.ui_mode {
+            Some(CheckoutSessionUiMode::Embedded) => {
+                if self.return_url.is_none() {
+                    return Err("`return_url` is required when `ui_mode` is `embedded`");
+                }
+            }
+            None | Some(CheckoutSessionUiMode::Hosted) => {
+                if self.success_url.is_none() {
+                    return Err("`success_url` is required when `ui_mode` is `hosted`");
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A Checkout Session, as returned by the Stripe API.
+///
+/// For more details see [https://stripe.com/docs/api/checkout/sessions/object](https://stripe.com/docs/api/checkout/sessions/object).
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSession {
+    /// Unique identifier for the object.
+    pub id: String,
+
+    /// String representing the object's type.
+    ///
+    /// Objects of the same type share the same value.
+    pub object: String,
+
+    /// A unique string to reference the Checkout Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_reference_id: Option<String>,
+
+    /// The ID of the customer for this Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<String>,
+
+    /// The customer's email address entered or provided for this Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_email: Option<String>,
+
+    /// The mode of the Checkout Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<CheckoutSessionMode>,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    /// The total of all items before any discounts or taxes are applied, in the smallest currency unit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_subtotal: Option<i64>,
+
+    /// The total, in the smallest currency unit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_total: Option<i64>,
+
+    /// The payment status of the Checkout Session.
+    ///
+    /// One of `paid`, `unpaid`, or `no_payment_required`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_status: Option<String>,
+
+    /// The status of the Checkout Session.
+    ///
+    /// One of `open`, `complete`, or `expired`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// The ID of the PaymentIntent for Checkout Sessions in `payment` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<String>,
+
+    /// The ID of the SetupIntent for Checkout Sessions in `setup` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_intent: Option<String>,
+
+    /// The ID of the Subscription for Checkout Sessions in `subscription` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<String>,
+
+    /// The URL the customer will be directed to after a successful payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success_url: Option<String>,
+
+    /// The URL the customer will be directed to if they decide to cancel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_url: Option<String>,
+
+    /// The URL to the Checkout Session.
+    ///
+    /// Only present when `ui_mode` is `hosted`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// The UI mode of the Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ui_mode: Option<CheckoutSessionUiMode>,
+
+    /// The client secret of the Session.
+    ///
+    /// Use this with the embedded Checkout frontend component to mount the
+    /// session inline. Only present when `ui_mode` is `embedded`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+
+    /// The IETF language tag of the locale the Checkout Session is displayed in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<CheckoutSessionLocale>,
+
+    /// Set of key-value pairs attached to the object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The Epoch time in seconds at which the Checkout Session will expire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<crate::Timestamp>,
+
+    /// When set, configuration for actions after the Checkout Session has
+    /// expired, including the generated recovery URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_expiration: Option<CheckoutSessionAfterExpiration>,
+
+    /// The ID of the original expired Checkout Session that this session was
+    /// recovered from, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovered_from: Option<String>,
+
+    /// Collected custom fields and the values entered by the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CheckoutSessionCustomField>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionAfterExpiration {
+    /// When set, configuration for the recovery of this Checkout Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovery: Option<CheckoutSessionAfterExpirationRecovery>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionAfterExpirationRecovery {
+    /// Enables user redeemable promotion codes on the recovered Checkout Sessions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_promotion_codes: Option<bool>,
+
+    /// If `true`, a recovery URL will be generated to recover this Checkout
+    /// Session if it expires before a successful transaction is completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// The timestamp at which the recovery URL will expire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<crate::Timestamp>,
+
+    /// URL that creates a new Checkout Session when clicked that is a copy of
+    /// this expired Checkout Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
 impl CheckoutSession {
-    /// Attach a payment method to a customer
+    /// Creates a Checkout Session.
     ///
-    /// For more details see [https://stripe.com/docs/api/payment_methods/attach](https://stripe.com/docs/api/payment_methods/attach).
+    /// For more details see [https://stripe.com/docs/api/checkout/sessions/create](https://stripe.com/docs/api/checkout/sessions/create).
     pub fn create(client: &Client, params: CreateCheckoutSession) -> Response<CheckoutSession> {
+        if let Err(err) = params.validate() {
+            return Err(crate::error::Error::ClientError(err.to_string()));
+        }
         client.post_form("/checkout/sessions", params)
     }
 }
@@ -0,0 +1,123 @@
+use crate::config::{Client, Response};
+use crate::params::{List, Object};
+use serde::de::DeserializeOwned;
+
+impl<T> List<T>
+where
+    T: Object + DeserializeOwned + 'static,
+    <T as Object>::Id: AsRef<str>,
+{
+    /// Walks every remaining page of the list and eagerly collects all
+    /// elements into a single `Vec`.
+    ///
+    /// Starting from this page, the same request is reissued with
+    /// `starting_after` set to the `id` of the last element until the server
+    /// reports `has_more == false` (or returns an empty page). The original
+    /// query parameters — `limit`, filters and so on — must be passed back in
+    /// via `params` so they are carried over into each follow-up request; the
+    /// listing's `url` is the bare resource path without a query string.
+    pub fn get_all(self, client: &Client, params: &[(&str, &str)]) -> Response<Vec<T>> {
+        let mut data = self.data;
+        let mut has_more = self.has_more;
+        let url = self.url;
+        while has_more {
+            let last_id = match data.last() {
+                Some(item) => item.id(),
+                None => break,
+            };
+            let mut query: Vec<(&str, &str)> = params.to_vec();
+            query.push(("starting_after", last_id.as_ref()));
+            let page: List<T> = client.get_query(&url, &query)?;
+            if page.data.is_empty() {
+                break;
+            }
+            has_more = page.has_more;
+            data.extend(page.data);
+        }
+        Ok(data)
+    }
+
+    /// Lazily walks the remaining pages of the list, yielding one element at a
+    /// time.
+    ///
+    /// Unlike [`List::get_all`], which buffers every element up front, this
+    /// fetches each page only as the previous one is exhausted. The original
+    /// query parameters must be passed back in via `params` so they are
+    /// preserved across follow-up requests. Each item is yielded as a
+    /// [`Response`]; a transport error from a follow-up request is yielded as
+    /// the final element before iteration stops.
+    pub fn paginate(self, client: &Client, params: &[(&str, &str)]) -> ListPaginator<'_, T> {
+        ListPaginator {
+            client,
+            page: self.data.into_iter(),
+            url: self.url,
+            params: params.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            has_more: self.has_more,
+            last_id: None,
+        }
+    }
+}
+
+/// A lazy iterator over every element of a paginated [`List`].
+///
+/// Created by [`List::paginate`].
+pub struct ListPaginator<'a, T> {
+    client: &'a Client,
+    page: std::vec::IntoIter<T>,
+    url: String,
+    params: Vec<(String, String)>,
+    has_more: bool,
+    last_id: Option<String>,
+}
+
+impl<'a, T> Iterator for ListPaginator<'a, T>
+where
+    T: Object + DeserializeOwned + 'static,
+    <T as Object>::Id: AsRef<str>,
+{
+    type Item = Response<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.page.next() {
+            self.last_id = Some(item.id().as_ref().to_string());
+            return Some(Ok(item));
+        }
+        if !self.has_more {
+            return None;
+        }
+        let last_id = self.last_id.take()?;
+        let mut query: Vec<(&str, &str)> =
+            self.params.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+        query.push(("starting_after", last_id.as_str()));
+        match self.client.get_query::<List<T>>(&self.url, &query) {
+            Ok(page) => {
+                if page.data.is_empty() {
+                    self.has_more = false;
+                    return None;
+                }
+                self.has_more = page.has_more;
+                self.page = page.data.into_iter();
+                self.next()
+            }
+            Err(err) => {
+                self.has_more = false;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl Client {
+    /// Eagerly fetches every page of `list`, collecting all elements.
+    ///
+    /// Convenience wrapper around [`List::get_all`]; `params` must be the
+    /// original query parameters used to produce `list` so that `limit` and any
+    /// filters are preserved across follow-up requests.
+    pub fn get_all<T>(&self, list: List<T>, params: &[(&str, &str)]) -> Response<Vec<T>>
+    where
+        T: Object + DeserializeOwned + 'static,
+        <T as Object>::Id: AsRef<str>,
+    {
+        list.get_all(self, params)
+    }
+}
@@ -0,0 +1,9 @@
+mod billing_meter_ext;
+mod checkout_session_ext;
+mod list_ext;
+mod webhook;
+
+pub use self::billing_meter_ext::*;
+pub use self::checkout_session_ext::*;
+pub use self::list_ext::*;
+pub use self::webhook::*;
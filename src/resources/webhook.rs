@@ -0,0 +1,253 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac, NewMac};
+use serde_derive::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::resources::CheckoutSession;
+
+/// The default tolerance, in seconds, between the timestamp in the
+/// `Stripe-Signature` header and the current time before a payload is
+/// rejected as a possible replay.
+const DEFAULT_TOLERANCE: i64 = 300;
+
+/// An error encountered while verifying or deserializing a webhook event.
+#[derive(Debug)]
+pub enum WebhookError {
+    /// The `Stripe-Signature` header was missing a timestamp or signature.
+    BadHeader,
+    /// None of the signatures in the header matched the expected signature.
+    BadSignature,
+    /// The timestamp in the header was older than the tolerance allows.
+    BadTimestamp(i64),
+    /// The payload could not be deserialized into a [`WebhookEvent`].
+    BadParse(serde_json::Error),
+}
+
+impl std::fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookError::BadHeader => write!(f, "invalid Stripe-Signature header"),
+            WebhookError::BadSignature => write!(f, "no signatures matched the expected signature"),
+            WebhookError::BadTimestamp(t) => write!(f, "timestamp outside the tolerance zone ({})", t),
+            WebhookError::BadParse(e) => write!(f, "failed to deserialize webhook payload: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for WebhookError {}
+
+impl From<serde_json::Error> for WebhookError {
+    fn from(err: serde_json::Error) -> Self {
+        WebhookError::BadParse(err)
+    }
+}
+
+/// A parsed and signature-verified webhook event.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WebhookEvent {
+    /// The unique identifier of the event.
+    pub id: String,
+
+    /// The type of the event, together with its typed data.
+    #[serde(flatten)]
+    pub data: EventType,
+}
+
+/// The set of event types this crate can deserialize, keyed on the `type` field
+/// of the event.
+///
+/// Unrecognized event types deserialize into [`EventType::Unknown`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", content = "data")]
+pub enum EventType {
+    #[serde(rename = "checkout.session.completed")]
+    CheckoutSessionCompleted(EventData<CheckoutSession>),
+    #[serde(rename = "checkout.session.async_payment_succeeded")]
+    CheckoutSessionAsyncPaymentSucceeded(EventData<CheckoutSession>),
+    #[serde(rename = "checkout.session.async_payment_failed")]
+    CheckoutSessionAsyncPaymentFailed(EventData<CheckoutSession>),
+    #[serde(rename = "checkout.session.expired")]
+    CheckoutSessionExpired(EventData<CheckoutSession>),
+    #[serde(other)]
+    Unknown,
+}
+
+/// The `data` envelope wrapping the resource an event refers to.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EventData<T> {
+    /// The resource as it exists after the event occurred.
+    pub object: T,
+}
+
+/// Verifies the signature of incoming webhook events and deserializes them.
+pub struct Webhook;
+
+impl Webhook {
+    /// Verifies the `Stripe-Signature` header against `payload` using `secret`
+    /// and, on success, deserializes the payload into a [`WebhookEvent`].
+    ///
+    /// Events older than the default tolerance of 300 seconds are rejected to
+    /// guard against replay attacks. Use [`Webhook::construct_event_with_tolerance`]
+    /// to configure the tolerance.
+    pub fn construct_event(
+        payload: &str,
+        sig_header: &str,
+        secret: &str,
+    ) -> Result<WebhookEvent, WebhookError> {
+        Self::construct_event_with_tolerance(payload, sig_header, secret, DEFAULT_TOLERANCE)
+    }
+
+    /// Like [`Webhook::construct_event`], but with a configurable replay
+    /// `tolerance` in seconds.
+    pub fn construct_event_with_tolerance(
+        payload: &str,
+        sig_header: &str,
+        secret: &str,
+        tolerance: i64,
+    ) -> Result<WebhookEvent, WebhookError> {
+        let signature = Signature::parse(sig_header)?;
+
+        // Check the timestamp is within tolerance to prevent replay attacks.
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or_default();
+        if now - signature.timestamp > tolerance {
+            return Err(WebhookError::BadTimestamp(signature.timestamp));
+        }
+
+        // Compute the expected signature over `"{t}.{payload}"`.
+        let signed_payload = format!("{}.{}", signature.timestamp, payload);
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .map_err(|_| WebhookError::BadSignature)?;
+        mac.update(signed_payload.as_bytes());
+        let expected = hex::encode(mac.finalize().into_bytes());
+
+        // Compare against each provided `v1` signature in constant time.
+        if !signature.signatures.iter().any(|sig| constant_time_eq(sig.as_bytes(), expected.as_bytes())) {
+            return Err(WebhookError::BadSignature);
+        }
+
+        Ok(serde_json::from_str(payload)?)
+    }
+}
+
+/// The parsed contents of a `Stripe-Signature` header.
+struct Signature<'a> {
+    timestamp: i64,
+    signatures: Vec<&'a str>,
+}
+
+impl<'a> Signature<'a> {
+    fn parse(header: &'a str) -> Result<Signature<'a>, WebhookError> {
+        let mut timestamp = None;
+        let mut signatures = Vec::new();
+        for pair in header.split(',') {
+            let mut parts = pair.splitn(2, '=');
+            match (parts.next(), parts.next()) {
+                (Some("t"), Some(value)) => {
+                    timestamp = value.trim().parse().ok();
+                }
+                (Some("v1"), Some(value)) => signatures.push(value.trim()),
+                _ => {}
+            }
+        }
+        match timestamp {
+            Some(timestamp) if !signatures.is_empty() => Ok(Signature { timestamp, signatures }),
+            _ => Err(WebhookError::BadHeader),
+        }
+    }
+}
+
+/// Compares two byte slices in constant time to avoid leaking information
+/// through timing side channels.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut result = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        result |= x ^ y;
+    }
+    result == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "whsec_test_secret";
+    const PAYLOAD: &str = r#"{"id":"evt_1","type":"checkout.session.completed","data":{"object":{"id":"cs_test_123","object":"checkout.session"}}}"#;
+
+    /// Computes a valid `v1` signature over `"{t}.{payload}"`.
+    fn sign(secret: &str, t: i64, payload: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(format!("{}.{}", t, payload).as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    fn now() -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn accepts_a_valid_signature() {
+        let t = now();
+        let header = format!("t={},v1={}", t, sign(SECRET, t, PAYLOAD));
+        let event = Webhook::construct_event(PAYLOAD, &header, SECRET).unwrap();
+        assert_eq!(event.id, "evt_1");
+        assert!(matches!(event.data, EventType::CheckoutSessionCompleted(_)));
+    }
+
+    #[test]
+    fn rejects_a_tampered_payload() {
+        let t = now();
+        let header = format!("t={},v1={}", t, sign(SECRET, t, PAYLOAD));
+        let tampered = PAYLOAD.replace("cs_test_123", "cs_test_evil");
+        let err = Webhook::construct_event(&tampered, &header, SECRET).unwrap_err();
+        assert!(matches!(err, WebhookError::BadSignature));
+    }
+
+    #[test]
+    fn rejects_a_malformed_header() {
+        let err = Webhook::construct_event(PAYLOAD, "not-a-valid-header", SECRET).unwrap_err();
+        assert!(matches!(err, WebhookError::BadHeader));
+    }
+
+    #[test]
+    fn rejects_a_header_without_a_signature() {
+        let header = format!("t={}", now());
+        let err = Webhook::construct_event(PAYLOAD, &header, SECRET).unwrap_err();
+        assert!(matches!(err, WebhookError::BadHeader));
+    }
+
+    #[test]
+    fn accepts_one_of_several_v1_signatures() {
+        let t = now();
+        let header = format!("t={},v1={},v1={}", t, "deadbeef", sign(SECRET, t, PAYLOAD));
+        let event = Webhook::construct_event(PAYLOAD, &header, SECRET).unwrap();
+        assert_eq!(event.id, "evt_1");
+    }
+
+    #[test]
+    fn rejects_a_replayed_event_outside_tolerance() {
+        let t = now() - 600;
+        let header = format!("t={},v1={}", t, sign(SECRET, t, PAYLOAD));
+        let err = Webhook::construct_event_with_tolerance(PAYLOAD, &header, SECRET, 300)
+            .unwrap_err();
+        assert!(matches!(err, WebhookError::BadTimestamp(_)));
+    }
+
+    #[test]
+    fn accepts_a_future_dated_timestamp() {
+        // Stripe only rejects events that are too old, never future-dated ones.
+        let t = now() + 600;
+        let header = format!("t={},v1={}", t, sign(SECRET, t, PAYLOAD));
+        let event = Webhook::construct_event_with_tolerance(PAYLOAD, &header, SECRET, 300).unwrap();
+        assert_eq!(event.id, "evt_1");
+    }
+}